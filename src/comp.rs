@@ -4,14 +4,21 @@ use crate::*;
 /// A component.
 pub trait NodeComponent: Downcast {
     /// Checks if this component has the given type-id.
+    /// 
+    /// **Note:**
+    /// > For a [`NamedComponent`] (or [`NamedComponentSync`]) this checks the type of the wrapped component,
+    /// > as that is the type it is stored and looked up by.
     fn is_of_type(&self, ctype: TypeId) -> bool {
-        self.type_id() == ctype
+        self.get_component_type_id() == ctype
     }
     
-    // /// Returns the type-id for this component.
-    // fn get_component_type_id(&self) -> TypeId {
-    //     self.type_id()
-    // }
+    /// Returns the type-id for this component, looking through any [`NamedComponent`] wrappers (and boxes).
+    fn get_component_type_id(&self) -> TypeId {
+        match unwrap_component(self.as_any()) {
+            Some(inner) => inner.get_component_type_id(),
+            None => self.type_id(),
+        }
+    }
     
     /// Returns a internal name for the component.
     fn get_component_name(&self) -> &str;
@@ -20,17 +27,32 @@ pub trait NodeComponent: Downcast {
 // Automatic impl for sized components.
 impl<C: Sized + 'static> NodeComponent for C where C: Sized {
     fn get_component_name(&self) -> &str {
-        std::any::type_name::<C>()
+        let any = self.as_any();
+        
+        // A boxed component is named after its contents.
+        if let Some(boxed) = any.downcast_ref::<NodeComponentBox>() {
+            return boxed.as_ref().get_component_name()
+        }
+        
+        match unwrap_named(any) {
+            Some((name, _)) => name,
+            None => std::any::type_name::<C>(),
+        }
     }
 }
 
 /// A component that is also Send/Sync.
 pub trait NodeComponentSync: NodeComponent + DowncastSync + Send + Sync {
-    //
+    /// Returns this component as a plain [`NodeComponent`].
+    fn as_node_component(&self) -> &dyn NodeComponent;
 }
 
 // Automatic impl for Send/Sync components.
-impl<C: NodeComponent> NodeComponentSync for C where C: Send + Sync {}
+impl<C: NodeComponent> NodeComponentSync for C where C: Send + Sync {
+    fn as_node_component(&self) -> &dyn NodeComponent {
+        self
+    }
+}
 
 /// A box holding a NodeComponent instance.
 pub type NodeComponentBox = Box<dyn NodeComponent>;
@@ -39,3 +61,168 @@ pub type NodeComponentBox = Box<dyn NodeComponent>;
 use downcast_rs::impl_downcast;
 impl_downcast!(NodeComponent);
 impl_downcast!(NodeComponentSync);
+
+/// A [`NodeComponent`] with a custom name, instead of its type-name.
+/// 
+/// Lookups by the type of the wrapped component will find the wrapper,
+/// and downcasting it yields the wrapped component.
+/// 
+/// **Note:**
+/// > The wrapped component is type-erased, so that the blanket [`NodeComponent`]-impl can recognize the wrapper.
+/// > 
+/// > Use `downcast_component_ref` and `downcast_component_mut`
+/// > instead of plain downcasting to look through it.
+pub struct NamedComponent {
+    /// The name of the component.
+    name: Arc<str>,
+    /// The wrapped component.
+    inner: Box<dyn NodeComponent>,
+}
+
+impl NamedComponent {
+    /// Wraps the given component, giving it the given name.
+    pub fn new<C: NodeComponent>(name: impl Into<Arc<str>>, comp: C) -> Self {
+        Self {
+            name: name.into(),
+            inner: Box::new(comp),
+        }
+    }
+    
+    /// Returns a reference to the wrapped component.
+    pub fn get_inner(&self) -> &dyn NodeComponent {
+        self.inner.as_ref()
+    }
+    
+    /// Returns a mutable reference to the wrapped component.
+    pub fn get_inner_mut(&mut self) -> &mut dyn NodeComponent {
+        self.inner.as_mut()
+    }
+}
+
+/// A [`NodeComponentSync`] with a custom name, instead of its type-name.
+/// 
+/// Lookups by the type of the wrapped component will find the wrapper,
+/// and downcasting it yields the wrapped component.
+pub struct NamedComponentSync {
+    /// The name of the component.
+    name: Arc<str>,
+    /// The wrapped component.
+    inner: Arc<dyn NodeComponentSync>,
+}
+
+impl NamedComponentSync {
+    /// Wraps the given component, giving it the given name.
+    pub fn new<C: NodeComponentSync>(name: impl Into<Arc<str>>, comp: C) -> Self {
+        Self {
+            name: name.into(),
+            inner: Arc::new(comp),
+        }
+    }
+    
+    /// Returns a reference to the wrapped component.
+    pub fn get_inner(&self) -> &Arc<dyn NodeComponentSync> {
+        &self.inner
+    }
+}
+
+/// Returns the name and component of a [`NamedComponent`] or [`NamedComponentSync`], if any.
+fn unwrap_named(any: &dyn std::any::Any) -> Option<(&str, &dyn NodeComponent)> {
+    if let Some(named) = any.downcast_ref::<NamedComponent>() {
+        return Some((&named.name, named.inner.as_ref()))
+    }
+    
+    if let Some(named) = any.downcast_ref::<NamedComponentSync>() {
+        return Some((&named.name, (*named.inner).as_node_component()))
+    }
+    
+    None
+}
+
+/// Returns the component wrapped by a [`NamedComponent`], [`NamedComponentSync`] or [`NodeComponentBox`], if any.
+fn unwrap_component(any: &dyn std::any::Any) -> Option<&dyn NodeComponent> {
+    if let Some(boxed) = any.downcast_ref::<NodeComponentBox>() {
+        return Some(boxed.as_ref())
+    }
+    
+    unwrap_named(any).map(|(_, inner)| inner)
+}
+
+impl dyn NodeComponent {
+    /// Downcasts this component to `C`, looking through any [`NamedComponent`] wrappers (and boxes).
+    pub fn downcast_component_ref<C: NodeComponent>(&self) -> Option<&C> {
+        if let Some(c) = self.downcast_ref::<C>() {
+            return Some(c)
+        }
+        
+        unwrap_component(self.as_any()).and_then(|inner| inner.downcast_component_ref::<C>())
+    }
+    
+    /// Mutably downcasts this component to `C`, looking through any [`NamedComponent`] wrappers (and boxes).
+    /// 
+    /// **Note:**
+    /// > A [`NamedComponentSync`] shares its component, so it can't be looked through here.
+    pub fn downcast_component_mut<C: NodeComponent>(&mut self) -> Option<&mut C> {
+        if self.is::<C>() {
+            return self.downcast_mut::<C>()
+        }
+        
+        // Components stored via `CStoreEventHandler::insert_cell` are still boxed.
+        if self.is::<NodeComponentBox>() {
+            return self.downcast_mut::<NodeComponentBox>()
+                .and_then(|boxed| boxed.as_mut().downcast_component_mut::<C>())
+        }
+        
+        self.downcast_mut::<NamedComponent>()
+            .and_then(|named| named.get_inner_mut().downcast_component_mut::<C>())
+    }
+}
+
+impl dyn NodeComponentSync {
+    /// Downcasts this component to `C`, looking through any [`NamedComponentSync`] wrappers.
+    pub fn downcast_component_arc<C: NodeComponentSync>(self: Arc<Self>) -> Option<Arc<C>> {
+        match self.into_any_arc().downcast::<C>() {
+            Ok(c) => Some(c),
+            Err(any) => any.downcast::<NamedComponentSync>()
+                .ok()
+                .and_then(|named| named.inner.clone().downcast_component_arc::<C>()),
+        }
+    }
+}
+
+/// Make sure that named components report their name and downcast to their inner type.
+#[test]
+fn test_named_component() {
+    let named: Box<dyn NodeComponent> = Box::new(NamedComponent::new("Greeting", "hello".to_string()));
+    let named_ref = named.as_ref();
+    assert_eq!(named_ref.get_component_name(), "Greeting");
+    assert_eq!(named_ref.get_component_type_id(), TypeId::of::<String>());
+    assert!(named_ref.is_of_type(TypeId::of::<String>()));
+    assert!(!named_ref.is_of_type(TypeId::of::<NamedComponent>()));
+    assert_eq!(named_ref.downcast_component_ref::<String>().map(String::as_str), Some("hello"));
+    
+    let shared: Arc<dyn NodeComponentSync> = Arc::new(NamedComponentSync::new("Counter", 42u32));
+    assert_eq!(shared.as_ref().get_component_name(), "Counter");
+    assert_eq!(shared.clone().downcast_component_arc::<u32>().as_deref(), Some(&42));
+    
+    let plain: &dyn NodeComponent = &7u8;
+    assert_eq!(plain.get_component_name(), "u8");
+    
+    let mut cstore = node::cstore::CStoreEventHandler::default();
+    assert!(cstore.insert_box(named));
+    assert!(cstore.insert_arc(shared));
+    assert!(cstore.insert_cell(Box::new(NamedComponent::new("Score", 5u32))));
+    
+    let mut backbone = Backbone::from(cstore);
+    assert_eq!(backbone.get_component::<String>().map(String::as_str), Some("hello"));
+    assert_eq!(backbone.get_component_arc::<u32>().as_deref(), Some(&42));
+    
+    let ctx = backbone.get_context().unwrap();
+    assert_eq!(ctx.get_component::<String>().map(String::as_str), Some("hello"));
+    assert_eq!(ctx.get_component_arc::<u32>().as_deref(), Some(&42));
+    
+    let cell = ctx.get_component_mut::<u32>().expect("celled component must be found by its inner type");
+    *cell.borrow_mut().downcast_component_mut::<u32>().expect("celled component must downcast") += 1;
+    let comp = cell.borrow();
+    assert_eq!(comp.downcast_component_ref::<u32>(), Some(&6));
+    assert_eq!((*comp).get_component_name(), "Score");
+}
@@ -34,7 +34,7 @@ impl<'c> NodeContext<'c> {
         
         for node in self.cons.iter().rev() {
             if let Some(c) = node.node.get_comp(type_id) {
-                return c.downcast_ref()
+                return c.downcast_component_ref()
             }
         }
         
@@ -42,6 +42,8 @@ impl<'c> NodeContext<'c> {
     }
     
     /// Returns a [`std::cell::RefCell`]'d [`NodeComponent`] of the given type `C`, if one exists.
+    /// 
+    /// Use `downcast_component_mut` on the borrowed component to get at the `C`.
     pub fn get_cons_component_mut<C: NodeComponent + 'static>(&self) -> Option<&RefCell<dyn NodeComponent>> {
         let type_id = TypeId::of::<C>();
         
@@ -60,9 +62,9 @@ impl<'c> NodeContext<'c> {
         
         for node in self.cons.iter().rev() {
             if let Some(c) = node.node.get_comp_arc(type_id) {
-                match c.into_any_arc().downcast::<C>() {
-                    Ok(c) => return Some(c),
-                    Err(_e) => continue,
+                match c.downcast_component_arc::<C>() {
+                    Some(c) => return Some(c),
+                    None => continue,
                 }
             }
         }
@@ -149,12 +151,20 @@ impl<'c> OuterNodeContext<'c> {
     }
     
     /// Fires an [`Event`] to run trough the backbone to the current node.
-    pub fn process_event<E: Event>(&mut self, event: &mut E) {
-        self.process_event_wrapper(EventWrapper::new(event));
+    /// 
+    /// Returns if any node marked the event as handled.
+    pub fn process_event<E: Event>(&mut self, event: &mut E) -> bool {
+        self.process_event_wrapper(EventWrapper::new(event))
     }
     
     /// Fires an [`Event`], wrapped in a [`EventWrapper`], to run trough the backbone to the current node.
-    pub fn process_event_wrapper(&mut self, mut wrapper: EventWrapper) {
+    /// 
+    /// Returns if any node marked the event as handled.
+    /// 
+    /// **Note:**
+    /// > Sub-events returned by handlers are separate events;
+    /// > them being handled does *not* mark the original event as handled.
+    pub fn process_event_wrapper(&mut self, mut wrapper: EventWrapper) -> bool {
         
         // This walker will FALL down the backbone...
         let falling_walker = (0..self.context.cons.len())
@@ -211,8 +221,10 @@ impl<'c> OuterNodeContext<'c> {
                 if let Some(idx) = idx {
                     if let Some(mut ctx) = self.get_subcontext_after(idx + 1) {
                         // TODO: This impl is terrible. Find a better way.
-                        ctx.process_event_wrapper(EventWrapper {
+                        // The sub-event starts out unhandled, and its handling isn't ours to report.
+                        let _handled = ctx.process_event_wrapper(EventWrapper {
                             event: sub_event.as_mut(),
+                            handled: false,
                             ..wrapper
                         });
                     }
@@ -221,17 +233,20 @@ impl<'c> OuterNodeContext<'c> {
         }
         
         // We are done!
+        wrapper.is_handled()
     }
     
     /// Returns a reference to a [`NodeComponent`] of the given type `C`, if one exists.
     pub fn get_component<C: NodeComponent + 'static>(&self) -> Option<&C> {
         let type_id = TypeId::of::<C>();
         self.current.node.get_comp(type_id)
-            .and_then(|c| c.downcast_ref::<C>())
+            .and_then(|c| c.downcast_component_ref::<C>())
             .or_else(||self.context.get_cons_component::<C>())
     }
     
     /// Returns a reference to a [`NodeComponent`] of the given type `C`, if one exists.
+    /// 
+    /// Use `downcast_component_mut` on the borrowed component to get at the `C`.
     pub fn get_component_mut<C: NodeComponent + 'static>(&self) -> Option<&RefCell<dyn NodeComponent>> {
         let type_id = TypeId::of::<C>();
         self.current.node.get_comp_mut(type_id)
@@ -242,7 +257,7 @@ impl<'c> OuterNodeContext<'c> {
     pub fn get_component_arc<C: NodeComponentSync + 'static>(&self) -> Option<Arc<C>> {
         let type_id = TypeId::of::<C>();
         self.current.node.get_comp_arc(type_id)
-            .and_then(|c| c.into_any_arc().downcast::<C>().ok())
+            .and_then(|c| c.downcast_component_arc::<C>())
             .or_else(||self.context.get_cons_component_arc::<C>())
     }
     
@@ -284,9 +299,17 @@ impl Backbone {
         })
     }
     
+    /// Returns an [`OuterNodeContext`] focused on the parent of the current node, if there is one.
+    /// 
+    /// Returns `None` when the current node is the root.
+    pub fn get_parent_context(&mut self) -> Option<OuterNodeContext<'_>> {
+        let at = self.nodes.len().checked_sub(2)?;
+        self.get_subcontext_before(at)
+    }
+    
     /// Returns an [`OuterNodeContext`] focused on the node indicated by the `at`-parameter.
     pub(crate) fn get_subcontext_before(&mut self, at: usize) -> Option<OuterNodeContext> {
-        if at > self.nodes.len() {return None}
+        if at >= self.nodes.len() {return None}
         let (start, end) = self.nodes.split_at_mut(at);
         
         Some(OuterNodeContext {
@@ -301,12 +324,12 @@ impl Backbone {
     // TODO: Implement a `get_context_for(PATH)`-method.
     
     /// Returns a reference to a [`NodeComponent`] of the given type `C`, if one exists.
-    pub fn get_component<C: NodeComponent + 'static>(&self) -> Option<&dyn NodeComponent> {
+    pub fn get_component<C: NodeComponent + 'static>(&self) -> Option<&C> {
         let type_id = TypeId::of::<C>();
         
         for node in self.nodes.iter().rev() {
             if let Some(c) = node.node.get_comp(type_id) {
-                return Some(c)
+                return c.downcast_component_ref()
             }
         }
         
@@ -314,6 +337,8 @@ impl Backbone {
     }
     
     /// Returns a [`std::cell::RefCell`]'d [`NodeComponent`] of the given type `C`, if one exists.
+    /// 
+    /// Use `downcast_component_mut` on the borrowed component to get at the `C`.
     pub fn get_component_mut<C: NodeComponent + 'static>(&self) -> Option<&RefCell<dyn NodeComponent>> {
         let type_id = TypeId::of::<C>();
         
@@ -327,15 +352,97 @@ impl Backbone {
     }
     
     /// Returns an [`std::sync::Arc`]'d [`NodeComponent`] of the given type `C`, if one exists.
-    pub fn get_component_arc<C: NodeComponentSync + 'static>(&mut self) -> Option<Arc<dyn NodeComponentSync>> {
+    pub fn get_component_arc<C: NodeComponentSync + 'static>(&mut self) -> Option<Arc<C>> {
         let type_id = TypeId::of::<C>();
         
         for node in self.nodes.iter_mut().rev() {
             if let Some(c) = node.node.get_comp_arc(type_id) {
-                return Some(c)
+                return c.downcast_component_arc()
             }
         }
         
         None
     }
 }
+
+/// Make sure that the parent context is focused one level above the current node.
+#[test]
+fn test_parent_context() {
+    let mut backbone = Backbone::default();
+    assert!(backbone.get_parent_context().is_none());
+    
+    for name in ["/a", "/a/b"] {
+        backbone.nodes.push(NamedNodeHandlerBox {
+            name: name.into(),
+            node: Box::new(node::empty::EmptyEventHandler),
+        });
+    }
+    
+    let parent = backbone.get_parent_context().unwrap();
+    assert_eq!(parent.name.as_ref(), "/a");
+    assert_eq!(parent.cons.len(), 1);
+    assert_eq!(parent.cons[0].name.as_ref(), "/");
+}
+
+/// Make sure that handling an event is reported back to the dispatcher.
+#[test]
+fn test_event_handled() {
+    #[derive(Debug)]
+    struct ConsumedEvent;
+    impl Event for ConsumedEvent {}
+    
+    #[derive(Debug)]
+    struct ConsumingHandler;
+    impl NodeHandler for ConsumingHandler {
+        fn handle_event<'e>(
+            &'e mut self,
+            event: &'e mut EventWrapper,
+            _context: &'e mut NodeContext,
+        ) -> SubEvent {
+            if event.get_phase() == EventPhase::Acting && event.downcast_ref::<ConsumedEvent>().is_some() {
+                event.mark_handled();
+            }
+            None
+        }
+    }
+    
+    let mut backbone = Backbone::from(ConsumingHandler);
+    let mut ctx = backbone.get_context().unwrap();
+    assert!(ctx.process_event(&mut ConsumedEvent));
+    assert!(!ctx.process_event(&mut EmptyEvent));
+}
+
+/// Make sure that handling an event is reported back from any phase, not just the action.
+#[test]
+fn test_event_handled_while_flowing() {
+    /// A handler that handles every event in the given phase.
+    #[derive(Debug)]
+    struct PhaseHandler(EventPhase);
+    impl NodeHandler for PhaseHandler {
+        fn handle_event<'e>(
+            &'e mut self,
+            event: &'e mut EventWrapper,
+            _context: &'e mut NodeContext,
+        ) -> SubEvent {
+            if event.get_phase() == self.0 {
+                event.mark_handled();
+            }
+            None
+        }
+    }
+    
+    for phase in [EventPhase::Falling, EventPhase::Rising] {
+        let mut backbone = Backbone::from(PhaseHandler(phase));
+        backbone.nodes.push(NamedNodeHandlerBox {
+            name: "/child".into(),
+            node: Box::new(node::empty::EmptyEventHandler),
+        });
+        
+        let mut ctx = backbone.get_context().unwrap();
+        assert!(ctx.process_event(&mut EmptyEvent), "event must be handled during {phase}");
+    }
+    
+    // The root alone is only ever acted upon.
+    let mut backbone = Backbone::from(PhaseHandler(EventPhase::Falling));
+    assert!(!backbone.get_context().unwrap().process_event(&mut EmptyEvent));
+}
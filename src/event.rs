@@ -31,6 +31,9 @@ pub struct EventWrapper<'e> {
 
     /// Can the event flow back towards its source?
     pub(crate) can_rise: bool,
+
+    /// Has the event been handled by any node?
+    pub(crate) handled: bool,
 }
 
 impl<'e> EventWrapper<'e> {
@@ -42,6 +45,7 @@ impl<'e> EventWrapper<'e> {
             can_fall: true,
             can_eval: true,
             can_rise: true,
+            handled: false,
         }
     }
     
@@ -100,6 +104,18 @@ impl<'e> EventWrapper<'e> {
         }
     }
     
+    /// Marks the event as handled, to be reported back to the dispatcher.
+    /// 
+    /// This does *not* affect the flow of the event; use the `stop`-methods for that.
+    pub fn mark_handled(&mut self) {
+        self.handled = true;
+    }
+    
+    /// Has the event been handled by any node?
+    pub fn is_handled(&self) -> bool {
+        self.handled
+    }
+    
     /// Stop the event completely.
     pub fn stop(&mut self) {
         self.can_fall = false;
@@ -15,9 +15,9 @@ pub mod prelude {
     pub use futures::channel::oneshot::Sender as OneshotSender;
     pub use std::cell::RefCell;
     pub use std::sync::Arc;
-    pub use crate::comp::{NodeComponent, NodeComponentSync};
+    pub use crate::comp::{NodeComponent, NodeComponentSync, NamedComponent, NamedComponentSync};
     pub use crate::event::{Event, EventWrapper, EventPhase};
-    pub use crate::node::{NodeHandler, NodeHandlerRequestRes, NodeHandlerRequest, cstore::CStoreEventHandler};
+    pub use crate::node::{NodeHandler, NodeHandlerRequestRes, NodeHandlerRequest, NodeRequestError, NodeRetryPolicy, spawn_node_request, cstore::CStoreEventHandler};
     pub use crate::ctx::{OuterNodeContext, NodeContext};
     pub use crate::Backbone;
 }
@@ -50,6 +50,12 @@ pub struct Backbone {
     
     /// Queue of thunks.
     thunks: Thunks,
+    
+    /// Names of the nodes on the path descended during the current navigation.
+    trail: Vec<Arc<str>>,
+    
+    /// How to deal with nodes that aren't ready yet.
+    retry: NodeRetryPolicy,
 }
 
 // Constructors.
@@ -68,12 +74,19 @@ impl Backbone {
                 node: root_handler
             }],
             thunks: Thunks::default(),
+            trail: Vec::default(),
+            retry: NodeRetryPolicy::default(),
         }
     }
     
+    /// Sets the policy for repeating node requests that aren't ready yet.
+    pub fn with_retry_policy(self, retry: NodeRetryPolicy) -> Self {
+        Self { retry, ..self }
+    }
+    
     /// Wraps the current root with the given handler to form a cascade.
     pub fn cascade<N: NodeHandler + 'static>(self, handler: N) -> Self {
-        let Self { mut nodes, .. } = self;
+        let Self { mut nodes, retry, .. } = self;
         if nodes.len() != 1 {panic!("can only cascade when the root is the sole node")}
         let node = nodes.remove(0);
         let handler = Box::new(handler);
@@ -90,6 +103,8 @@ impl Backbone {
                 })
             }],
             thunks: Thunks::default(),
+            trail: Vec::default(),
+            retry,
         }
     }
 }
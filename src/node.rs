@@ -84,6 +84,104 @@ pub type NodeHandlerRequest = futures::channel::oneshot::Receiver<NodeHandlerCre
 /// The result of a completed [`NodeHandlerRequest`].
 pub type NodeHandlerCreated = Result<NamedNodeHandlerBox, Box<dyn std::error::Error>>;
 
+/// Well-known errors for [`NodeHandler::handle_node_request`] to return.
+/// 
+/// i.e: `Err(NodeRequestError::Retry.into())`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeRequestError {
+    /// The node can't be created *yet* (i.e. an asset is still loading); ask again later.
+    /// 
+    /// How often and how soon the request is repeated is decided by the [`NodeRetryPolicy`] of the [`Backbone`].
+    Retry,
+}
+
+impl NodeRequestError {
+    /// Checks if the given error is a [`NodeRequestError::Retry`].
+    pub fn is_retry(error: &(dyn std::error::Error + 'static)) -> bool {
+        matches!(error.downcast_ref::<Self>(), Some(Self::Retry))
+    }
+}
+
+impl std::fmt::Display for NodeRequestError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Retry => write!(fmt, "node is not ready yet"),
+        }
+    }
+}
+
+impl std::error::Error for NodeRequestError {}
+
+/// Policy for repeating node requests that failed with [`NodeRequestError::Retry`].
+/// 
+/// A request is repeated up to `limit` times, waiting `delay` updates of the [`Backbone`] in between;
+/// once the limit is reached, the entire navigation is given up, as it would be with any other error.
+/// 
+/// Errors are retried both when returned directly by [`NodeHandler::handle_node_request`]
+/// and when delivered trough the [`NodeHandlerRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeRetryPolicy {
+    /// How often a request may be repeated.
+    pub limit: u32,
+    /// How many updates to wait before repeating a request.
+    pub delay: u32,
+}
+
+impl Default for NodeRetryPolicy {
+    fn default() -> Self {
+        Self {
+            limit: 3,
+            delay: 1,
+        }
+    }
+}
+
+/// Creates a [`NodeHandlerRequest`] that is fulfilled by the given future, spawned by the given function.
+/// 
+/// The spawn function is provided by the caller, allowing the use of any executor.
+/// 
+/// **Note:**
+/// > As [`NodeHandlerCreated`] is not [`Send`], the future must be spawned on a local executor.
+/// 
+/// i.e: Given a handler that holds a `futures::executor::LocalSpawner` ...
+/// ```rust
+/// # use firespine::prelude::*;
+/// # use firespine::node::{NamedNodeHandlerBox, empty::EmptyEventHandler};
+/// # use firespine::futures::{executor::LocalSpawner, task::LocalSpawnExt};
+/// # #[derive(Debug)]
+/// # struct MyNodeHandler { spawner: LocalSpawner }
+/// # impl NodeHandler for MyNodeHandler {
+/// fn handle_node_request<'e>(
+///     &'e mut self,
+///     name: Arc<str>,
+///     _context: &'e mut NodeContext,
+/// ) -> NodeHandlerRequestRes {
+///     spawn_node_request(
+///         async move {
+///             // Load whatever the node needs here...
+///             Ok(NamedNodeHandlerBox {name, node: Box::new(EmptyEventHandler)})
+///         },
+///         |fut| self.spawner.spawn_local(fut).expect("executor is gone")
+///     )
+/// }
+/// # }
+/// ```
+pub fn spawn_node_request(
+    fut: impl std::future::Future<Output = NodeHandlerCreated> + 'static,
+    spawn: impl FnOnce(futures::future::LocalBoxFuture<'static, ()>),
+) -> NodeHandlerRequestRes {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    
+    spawn(Box::pin(async move {
+        // If the receiver is gone, nobody is waiting for the node anymore.
+        if tx.send(fut.await).is_err() {
+            crate::debug!("Node creation completed, but the request was dropped.");
+        }
+    }));
+    
+    Ok(rx)
+}
+
 /// A box holding a [`NodeHandler`] instance.
 pub type NodeHandlerBox = Box<dyn NodeHandler>;
 
@@ -126,19 +224,19 @@ pub mod cstore {
     impl CStoreEventHandler {
         /// Adds a new [`NodeComponent`] to this store.
         pub fn insert_box(&mut self, comp: Box<dyn NodeComponent>) -> bool {
-            let type_id = comp.type_id();
+            let type_id = comp.as_ref().get_component_type_id();
             self.stored.insert(type_id, comp).is_none()
         }
         
         /// Adds a new [`NodeComponent`] to this store.
         pub fn insert_cell(&mut self, comp: Box<dyn NodeComponent>) -> bool {
-            let type_id = comp.type_id();
+            let type_id = comp.as_ref().get_component_type_id();
             self.celled.insert(type_id, Box::new(RefCell::new(comp))).is_none()
         }
         
         /// Adds a new [`NodeComponent`] to this store.
         pub fn insert_arc(&mut self, comp: Arc<dyn NodeComponentSync>) -> bool {
-            let type_id = comp.type_id();
+            let type_id = comp.as_ref().get_component_type_id();
             self.shared.insert(type_id, comp).is_none()
         }
     }
@@ -251,7 +349,11 @@ pub mod cascade {
             context: &'e mut NodeContext,
         ) -> SubEvent {
             match event.get_phase() {
-                EventPhase::Creation => unreachable!("This should never ever occur"),
+                EventPhase::Creation => {
+                    // The event was never dispatched; handling it here would be wrong.
+                    crate::warn!("Event {:?} reached {} during the Creation phase; ignoring.", event.get_event(), context.name);
+                    None
+                },
                 EventPhase::Falling => {
                     // TODO: Handle sub-event returned by the outer node.
                     self.outer.node.handle_event(event, context);
@@ -291,5 +393,96 @@ pub mod cascade {
             self.inner.node.get_comp_arc(ctype).or_else(||self.outer.node.get_comp_arc(ctype))
         }
     }
+    
+    /// Make sure that an event stuck in the creation phase doesn't blow up the cascade.
+    #[test]
+    fn test_cascade_creation_phase() {
+        type Seen = std::rc::Rc<RefCell<Vec<(&'static str, EventPhase)>>>;
+        
+        /// A handler that records the phases it sees events in.
+        #[derive(Debug)]
+        struct RecordingHandler(&'static str, Seen);
+        impl NodeHandler for RecordingHandler {
+            fn handle_event<'e>(
+                &'e mut self,
+                event: &'e mut EventWrapper,
+                _context: &'e mut NodeContext,
+            ) -> SubEvent {
+                self.1.borrow_mut().push((self.0, event.get_phase()));
+                None
+            }
+        }
+        
+        let seen = Seen::default();
+        let mut backbone = Backbone::from(RecordingHandler("inner", seen.clone()))
+            .cascade(RecordingHandler("outer", seen.clone()));
+        
+        backbone.nodes.push(NamedNodeHandlerBox {
+            name: "/child".into(),
+            node: Box::new(RecordingHandler("child", seen.clone())),
+        });
+        
+        let mut event = EmptyEvent;
+        let mut wrapper = EventWrapper::new(&mut event);
+        assert_eq!(wrapper.get_phase(), EventPhase::Creation);
+        
+        // Delivering it directly must be a no-op.
+        let mut root = backbone.get_root_context().unwrap();
+        let sub = root.current.node.handle_event(&mut wrapper, &mut root.context);
+        assert!(sub.is_none());
+        assert!(seen.borrow().is_empty());
+        
+        // Dispatching it properly must run every phase, in order.
+        backbone.get_context().unwrap().process_event_wrapper(wrapper);
+        assert_eq!(seen.borrow().as_slice(), &[
+            ("outer", EventPhase::Falling),
+            ("inner", EventPhase::Falling),
+            ("child", EventPhase::Acting),
+            ("inner", EventPhase::Rising),
+            ("outer", EventPhase::Rising),
+        ]);
+    }
+}
 
-}
\ No newline at end of file
+/// Make sure that a spawned node request mounts the node once the executor ran.
+#[test]
+fn test_spawn_node_request() {
+    use futures::executor::{LocalPool, LocalSpawner};
+    use futures::task::LocalSpawnExt;
+    
+    #[derive(Debug)]
+    struct SpawningHandler {
+        spawner: LocalSpawner,
+    }
+    
+    impl NodeHandler for SpawningHandler {
+        fn handle_node_request<'e>(
+            &'e mut self,
+            name: Arc<str>,
+            _context: &'e mut NodeContext,
+        ) -> NodeHandlerRequestRes {
+            spawn_node_request(
+                async move {
+                    Ok(NamedNodeHandlerBox {name, node: Box::new(empty::EmptyEventHandler)})
+                },
+                |fut| self.spawner.spawn_local(fut).unwrap()
+            )
+        }
+    }
+    
+    let mut pool = LocalPool::new();
+    let mut backbone = Backbone::from(SpawningHandler {
+        spawner: pool.spawner(),
+    });
+    
+    assert!(backbone.navigate("child"));
+    
+    for _ in 0..16 {
+        backbone.update().expect("spawned node request must not fail");
+        pool.run_until_stalled();
+        if backbone.is_idle() {break}
+    }
+    
+    assert!(backbone.is_idle());
+    assert_eq!(backbone.nodes.last().map(|n| n.name.as_ref()), Some("/child"));
+}
@@ -16,11 +16,14 @@ pub enum Thunk {
     /// Navigate to self (no-op).
     ToSelf,
     
-    /// Navigate to sub-node.
-    ToNode(String),
+    /// Navigate to sub-node; with the number of times the request was retried.
+    ToNode(String, u32),
     
-    /// Waiting for a node to construct itself.
-    Waiting(Arc<str>, NodeHandlerRequest),
+    /// Wait for the given number of updates.
+    Delay(u32),
+    
+    /// Waiting for a node to construct itself; with the sub-node and retry count it was requested by.
+    Waiting(Arc<str>, NodeHandlerRequest, String, u32),
     
     /// Something went horribly wrong.
     Error(Box<dyn std::error::Error>),
@@ -53,10 +56,10 @@ impl Thunk {
         }
         
         if let Some((current, next)) = path.split_once('/') {
-            return Some((Thunk::ToNode(current.to_owned()), next))
+            return Some((Thunk::ToNode(current.to_owned(), 0), next))
         }
         
-        Some((Thunk::ToNode(path.to_owned()), ""))
+        Some((Thunk::ToNode(path.to_owned(), 0), ""))
     }
 }
 
@@ -66,8 +69,9 @@ impl std::fmt::Display for Thunk {
 			Self::ToRoot => write!(fmt, "/"),
 			Self::ToSelf => write!(fmt, "./"),
 			Self::ToParent => write!(fmt, "../"),
-			Self::ToNode(name) => write!(fmt, "{name}"),
-			Self::Waiting(_, _) => write!(fmt, "!Waiting"),
+			Self::ToNode(name, _) => write!(fmt, "{name}"),
+			Self::Delay(ticks) => write!(fmt, "!Delay({ticks})"),
+			Self::Waiting(..) => write!(fmt, "!Waiting"),
 			Self::Error(e) => write!(fmt, "!Error: {e}"),
 			Self::End => write!(fmt, "!Ok"),
 		}
@@ -114,11 +118,6 @@ impl Backbone {
             return false;
         }
         
-        // Avoid infinite movement.
-        if self.thunks.len() > 16 {
-            return false;
-        }
-        
         let mut begin = events::NavigationBeginningEvent {
             cancel: false,
         };
@@ -183,6 +182,9 @@ impl Backbone {
             None => return Ok(()),
             
             Some(Thunk::End) => {
+                // Navigation is over; forget the nodes we've been to.
+                self.trail.clear();
+                
                 if self.thunks.is_empty() {
                     crate::info!("Navigation Complete: {}", self.path_as_string());
                     self.get_context()
@@ -195,15 +197,30 @@ impl Backbone {
             },
             
             Some(Thunk::Error(error)) => {
+                self.abort_navigation();
                 return Err(error);
             },
             
-            Some(Thunk::Waiting(nid, mut rx)) => {
+            Some(Thunk::Waiting(nid, mut rx, nn, retries)) => {
                 match rx.try_recv() {
                     Ok(response) => {
                         match response {
                             Some(result) => match result {
+                                Ok(node) if node.name != nid && self.trail.contains(&node.name) => {
+                                    // The handler gave us a node we've already been to...
+                                    Some(Thunk::Error(format!(
+                                        "Navigation loop detected: requested '{nid}', but got already visited node '{}'",
+                                        node.name
+                                    ).into()))
+                                },
                                 Ok(node) => {
+                                    // Remember the node by the name it actually has.
+                                    if node.name != nid {
+                                        if let Some(last) = self.trail.last_mut() {
+                                            *last = node.name.clone();
+                                        }
+                                    }
+                                    
                                     // Insert and jump into node...
                                     self.nodes.push(node);
                                     self.get_context()
@@ -211,12 +228,15 @@ impl Backbone {
                                         .process_event(&mut events::NavigationEnterEvent);
                                     None
                                 },
+                                Err(err) if NodeRequestError::is_retry(err.as_ref()) => {
+                                    self.retry_node(nn, retries, &nid, err)
+                                },
                                 Err(err) => {
                                     Some(Thunk::Error(err))
                                 },
                             },
                             None => {
-                                Some(Thunk::Waiting(nid, rx))
+                                Some(Thunk::Waiting(nid, rx, nn, retries))
                             },
                         }
                     },
@@ -226,18 +246,39 @@ impl Backbone {
                 }
             },
             
-            Some(Thunk::ToNode(nn)) => {
+            Some(Thunk::Delay(ticks)) => {
+                if ticks > 1 {
+                    Some(Thunk::Delay(ticks - 1))
+                } else {
+                    None
+                }
+            },
+            
+            Some(Thunk::ToNode(nn, retries)) => {
                 // Construct full name of child-node...
-                let mut ctx = self.get_context().unwrap();
-                let child_name = ctx.get_child_name(&nn);
+                let child_name = self.get_context().unwrap().get_child_name(&nn);
                 
-                match ctx.current.node.handle_node_request(child_name.clone(), &mut ctx.context) {
-                    Err(err) => {
-                        Some(Thunk::Error(err))
-                    },
-                    Ok(rx) => {
-                        Some(Thunk::Waiting(child_name, rx))
-                    },
+                // Requesting the same node twice in one navigation means we are going in circles.
+                if self.trail.contains(&child_name) {
+                    let trail = self.trail.join(" -> ");
+                    Some(Thunk::Error(format!(
+                        "Navigation loop detected: '{child_name}' was already requested: {trail}"
+                    ).into()))
+                } else {
+                    self.trail.push(child_name.clone());
+                    let mut ctx = self.get_context().unwrap();
+                    
+                    match ctx.current.node.handle_node_request(child_name.clone(), &mut ctx.context) {
+                        Err(err) if NodeRequestError::is_retry(err.as_ref()) => {
+                            self.retry_node(nn, retries, &child_name, err)
+                        },
+                        Err(err) => {
+                            Some(Thunk::Error(err))
+                        },
+                        Ok(rx) => {
+                            Some(Thunk::Waiting(child_name, rx, nn, retries))
+                        },
+                    }
                 }
             },
             
@@ -256,10 +297,16 @@ impl Backbone {
                         drop(node);
                     }
                 }
+                
+                // Leaving a node takes it off the active path.
+                self.trail.pop();
                 None
             },
             
             Some(Thunk::ToRoot) => {
+                // The entire active path is left behind.
+                self.trail.clear();
+                
                 if self.nodes.len() > 1 {
                     // Not yet at root, keep popping...
                     Some(Thunk::ToParent)
@@ -278,4 +325,166 @@ impl Backbone {
         // All is okay.
         Ok(())
     }
+    
+    /// Gives up on the current navigation, dropping the rest of its path.
+    fn abort_navigation(&mut self) {
+        self.trail.clear();
+        
+        while let Some(thunk) = self.thunks.pop_front() {
+            if matches!(thunk, Thunk::End) {
+                break;
+            }
+        }
+    }
+    
+    /// Re-queues the request for the sub-node `nn`, as per the [`NodeRetryPolicy`].
+    fn retry_node(&mut self, nn: String, retries: u32, child_name: &str, err: Box<dyn std::error::Error>) -> Option<Thunk> {
+        // Not a loop, just impatience; forget we've been here.
+        self.trail.pop();
+        
+        if retries >= self.retry.limit {
+            return Some(Thunk::Error(format!(
+                "Node '{child_name}' was still not ready after {retries} retries: {err}"
+            ).into()))
+        }
+        
+        crate::debug!("Node '{child_name}' is not ready yet; retry {} of {}.", retries + 1, self.retry.limit);
+        self.thunks.push_front(Thunk::ToNode(nn, retries + 1));
+        
+        if self.retry.delay > 0 {
+            Some(Thunk::Delay(self.retry.delay))
+        } else {
+            None
+        }
+    }
+}
+
+/// A handler for testing navigation; its children are configured the same.
+#[cfg(test)]
+#[derive(Debug, Clone, Default)]
+struct TestHandler {
+    /// Name all children this, instead of what was requested.
+    rename: Option<&'static str>,
+    /// Number of requests to answer with [`NodeRequestError::Retry`].
+    pending: u32,
+    /// Report retries trough the request, instead of directly?
+    deferred: bool,
+    /// Names of all children requested from this handler (and its children).
+    requested: std::rc::Rc<RefCell<Vec<Arc<str>>>>,
+}
+
+#[cfg(test)]
+impl NodeHandler for TestHandler {
+    fn handle_node_request<'e>(
+        &'e mut self,
+        name: Arc<str>,
+        _context: &'e mut NodeContext,
+    ) -> NodeHandlerRequestRes {
+        self.requested.borrow_mut().push(name.clone());
+        let (tx, rx) = futures::channel::oneshot::channel();
+        
+        if self.pending > 0 {
+            self.pending -= 1;
+            if !self.deferred {
+                return Err(NodeRequestError::Retry.into());
+            }
+            tx.send(Err(NodeRequestError::Retry.into())).unwrap();
+        } else {
+            let name = self.rename.map(Arc::from).unwrap_or(name);
+            let node = Box::new(TestHandler {pending: 0, ..self.clone()});
+            tx.send(Ok(NamedNodeHandlerBox {name, node})).unwrap();
+        }
+        
+        Ok(rx)
+    }
+}
+
+/// Runs the backbone until it is idle, or fails.
+#[cfg(test)]
+fn run_navigation(backbone: &mut Backbone) -> Result<(), Box<dyn std::error::Error>> {
+    for _ in 0..64 {
+        backbone.update()?;
+        if backbone.is_idle() {break}
+    }
+    Ok(())
+}
+
+/// Make sure that deep paths are navigable.
+#[test]
+fn test_deep_navigation() {
+    let mut backbone = Backbone::from(TestHandler::default());
+    let path = vec!["n"; 32].join("/");
+    assert!(backbone.navigate(&path));
+    
+    while backbone.is_moving() {
+        backbone.update().expect("deep navigation must not fail");
+    }
+    
+    assert_eq!(backbone.nodes.len(), 33);
+}
+
+/// Make sure that returning to a node that was left again is not a loop.
+#[test]
+fn test_return_navigation() {
+    let mut backbone = Backbone::from(TestHandler::default());
+    assert!(backbone.navigate("a/../a/b/../../a"));
+    
+    while backbone.is_moving() {
+        backbone.update().expect("returning navigation must not fail");
+    }
+    
+    assert_eq!(backbone.nodes.len(), 2);
+    assert_eq!(backbone.nodes.last().map(|n| n.name.as_ref()), Some("/a"));
+}
+
+/// Make sure that a handler going in circles is caught, and the navigation given up.
+#[test]
+fn test_looping_navigation() {
+    let mut backbone = Backbone::from(TestHandler {
+        rename: Some("/loop"),
+        ..Default::default()
+    });
+    assert!(backbone.navigate("a/b/c/d/e"));
+    
+    let error = run_navigation(&mut backbone).expect_err("looping navigation must fail");
+    assert!(error.to_string().contains("Navigation loop detected"));
+    
+    // Nothing of the failed navigation may remain.
+    for _ in 0..16 {
+        backbone.update().expect("failed navigation must not continue");
+    }
+    
+    assert!(backbone.is_idle());
+    assert_eq!(backbone.nodes.len(), 2);
+}
+
+/// Make sure that nodes which aren't ready yet are retried, but not forever.
+#[test]
+fn test_retry_navigation() {
+    let policy = NodeRetryPolicy {limit: 3, delay: 2};
+    
+    for deferred in [false, true] {
+        let mut backbone = Backbone::from(TestHandler {pending: 3, deferred, ..Default::default()})
+            .with_retry_policy(policy);
+        assert!(backbone.navigate("asset"));
+        run_navigation(&mut backbone).expect("node must mount within the retry limit");
+        assert_eq!(backbone.nodes.last().map(|n| n.name.as_ref()), Some("/asset"));
+        
+        let mut backbone = Backbone::from(TestHandler {pending: 4, deferred, ..Default::default()})
+            .with_retry_policy(policy);
+        assert!(backbone.navigate("asset"));
+        let error = run_navigation(&mut backbone).expect_err("node must not mount beyond the retry limit");
+        assert!(error.to_string().contains("still not ready"));
+        
+        // Giving up on a node gives up on the rest of the path, too.
+        let handler = TestHandler {pending: 4, deferred, ..Default::default()};
+        let requested = handler.requested.clone();
+        let mut backbone = Backbone::from(handler).with_retry_policy(policy);
+        assert!(backbone.navigate("asset/child"));
+        run_navigation(&mut backbone).expect_err("node must not mount beyond the retry limit");
+        run_navigation(&mut backbone).expect("failed navigation must not continue");
+        
+        assert!(backbone.is_idle());
+        assert!(requested.borrow().iter().all(|name| name.as_ref() == "/asset"));
+    }
 }